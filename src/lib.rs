@@ -8,7 +8,57 @@ use std::ops;
 
 use derive_more::{Add, AddAssign, Neg, Rem, Sub, SubAssign};
 
-const TWO_PI: f64 = 6.2831853071795865f64;
+const TWO_PI: f64 = std::f64::consts::TAU;
+
+/// Number of CORDIC iterations used by the `*_fixed` trigonometric methods.
+///
+/// 30 iterations give close to the full 31 bits of fractional precision offered by the Q1.31
+/// output format.
+const CORDIC_ITERS: usize = 30;
+
+/// The CORDIC gain `K = prod(1 / sqrt(1 + 2^(-2*i)))` for `i` in `0..CORDIC_ITERS`, pre-scaled
+/// into Q1.31 fixed point (`K ≈ 0.60725293`). Pre-multiplying the initial `x` register by this
+/// constant means the vector length is already normalized by the time the loop finishes, so `x`
+/// and `y` directly approximate `cos`/`sin` without a separate rescaling step.
+const CORDIC_GAIN_Q31: i32 = 1_304_065_748;
+
+/// `atan(2^-i)` for `i` in `0..CORDIC_ITERS`, pre-converted into the crate's `u32` turn units
+/// (where `0x1_0000_0000` would be a whole circle) so it can be subtracted from the `z` register
+/// directly.
+const CORDIC_ATAN_TABLE: [u32; CORDIC_ITERS] = [
+    536_870_912, 316_933_406, 167_458_907, 85_004_756, 42_667_331, 21_354_465, 10_679_838,
+    5_340_245, 2_670_163, 1_335_087, 667_544, 333_772, 166_886, 83_443, 41_722, 20_861, 10_430,
+    5_215, 2_608, 1_304, 652, 326, 163, 81, 41, 20, 10, 5, 3, 1,
+];
+
+/// `0.28` pre-scaled into Q1.31, the coefficient of the `atan(r) ≈ r / (1 + 0.28 * r²)`
+/// polynomial used by [`Angle::atan2_fixed`].
+const ATAN_POLY_COEFF_Q31: i64 = 601_295_421;
+
+/// `1 / (2π)` pre-scaled into Q1.31, used to convert the polynomial's radian result into the
+/// crate's turn-fraction units.
+const INV_TWO_PI_Q31: i64 = 341_782_638;
+
+/// Computes `atan(min(ax, ay) / max(ax, ay))`, reflected into `[0, π/2]` as needed, and returns
+/// it as a turn-fraction in `u32` angle units. Used by [`Angle::atan2_fixed`] after it has reduced
+/// the problem down to magnitudes in the first quadrant.
+fn atan_octant_turn_u32(ax: u64, ay: u64) -> u32 {
+    let swapped = ax < ay;
+    let (low, high) = if ax <= ay { (ax, ay) } else { (ay, ax) };
+
+    let r = ((low as i64) << 31) / (high as i64);
+    let r_squared = (r * r) >> 31;
+    let denominator = (1i64 << 31) + ((ATAN_POLY_COEFF_Q31 * r_squared) >> 31);
+    let theta_radians_q31 = (r << 31) / denominator;
+    let turn_q31 = (theta_radians_q31 * INV_TWO_PI_Q31) >> 31;
+    let angle = (turn_q31 << 1) as u32;
+
+    if swapped {
+        0x4000_0000u32.wrapping_sub(angle)
+    } else {
+        angle
+    }
+}
 
 /// A wrapping angle stored in the `u32` field.
 ///
@@ -21,6 +71,8 @@ const TWO_PI: f64 = 6.2831853071795865f64;
 /// multiplying an obtuse angle by 4 then dividing by 4 again yields an acute angle.
 /// This is because this struct does not store the number of revolutions it has wrapped.
 #[derive(Clone, Copy, Add, AddAssign, Sub, SubAssign, Neg, Rem, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Angle(pub Wrapping<u32>);
 
 impl Angle {
@@ -90,7 +142,7 @@ impl Angle {
     #[inline]
     pub fn from_unit(unit: f64) -> Self {
         assert!(
-            unit.is_finite() && unit >= 0.0 && unit <= 1.0,
+            unit.is_finite() && (0.0..=1.0).contains(&unit),
             "unit must be in the range [0, 1], got {:?}",
             unit
         );
@@ -115,7 +167,7 @@ impl Angle {
         // We convert to `u64` first, because `1 - f64::MACHINE_EPSILON` should give `0u32`
         // instead of `0x_7fff_ffff_u32`.
         Angle(Wrapping(
-            (0x1_0000_000u64 as f64 * unit).round() as u64 as u32
+            (0x1_0000_0000u64 as f64 * unit).round() as u64 as u32
         ))
     }
 
@@ -123,7 +175,7 @@ impl Angle {
     /// whole circle.
     #[inline]
     pub fn as_unit(self) -> f64 {
-        (self.0).0 as f64 / 0x1_0000_000_u64 as f64
+        (self.0).0 as f64 / 0x1_0000_0000_u64 as f64
     }
 
     /// Returns the angle as a value in the range [-0.5, 0.5), where 0 is zero angle and 0.25 is a
@@ -131,7 +183,7 @@ impl Angle {
     #[inline]
     pub fn as_signed_unit(self) -> f64 {
         // The number is first cast to i32 (a no-op) so that values greater than half become signed
-        (self.0).0 as i32 as f64 / 0x1_0000_000_u64 as f64
+        (self.0).0 as i32 as f64 / 0x1_0000_0000_u64 as f64
     }
 
     /// Creates an angle from the radians representation in the range [0, 2&pi;).
@@ -235,6 +287,137 @@ impl Angle {
         Self::from_radians(y.atan2(x))
     }
 
+    /// Computes the four-quadrant arctangent angle of a ratio entirely in integer arithmetic,
+    /// never touching `f64`.
+    ///
+    /// The signed `u32` field backing `Angle` already represents `[-π, π)` across the full `i32`
+    /// range, which maps naturally onto `atan2`'s usual codomain, so this is a drop-in
+    /// integer-only alternative to [`atan2`](Self::atan2) for callers (e.g. sensor fusion, compass
+    /// code) that already have integer vector components.
+    ///
+    /// The underlying approximation is accurate to a fraction of a degree.
+    pub fn atan2_fixed(y: i32, x: i32) -> Self {
+        let (ax, ay) = (i64::from(x).unsigned_abs(), i64::from(y).unsigned_abs());
+        if ax == 0 && ay == 0 {
+            return ZERO;
+        }
+
+        let octant_angle = atan_octant_turn_u32(ax, ay);
+        let angle = if x >= 0 && y >= 0 {
+            octant_angle
+        } else if x < 0 && y >= 0 {
+            0x8000_0000u32.wrapping_sub(octant_angle)
+        } else if x < 0 && y < 0 {
+            0x8000_0000u32.wrapping_add(octant_angle)
+        } else {
+            0u32.wrapping_sub(octant_angle)
+        };
+        Self::from_u32(angle)
+    }
+
+    /// Simultaneously computes the sine and cosine of this angle using integer-only CORDIC,
+    /// returning `(sin, cos)` in Q1.31 fixed point (i.e. `2^31` represents `1.0`; results are
+    /// saturated to the `i32` range, so `1.0` comes out as `i32::MAX`).
+    ///
+    /// Unlike [`sin_cos`](Self::sin_cos), this never touches `f64`, making it suitable for
+    /// embedded or no-float targets.
+    ///
+    /// ```
+    /// use wrapang::Angle;
+    ///
+    /// let (sin, cos) = Angle::from_degrees(30.0).sin_cos_fixed();
+    /// assert!((sin - (0.5 * i32::MAX as f64) as i32).abs() < 1000);
+    /// assert!((cos - (0.866_025_4 * i32::MAX as f64) as i32).abs() < 1000);
+    ///
+    /// // The cardinal bearings land exactly on the Q1.31 boundary (`sin`/`cos` of `1.0` maps to
+    /// // `2^31`, one past `i32::MAX`), which used to overflow; they must saturate instead.
+    /// assert!(Angle::from_degrees(0.0).cos_fixed() > i32::MAX - 1000);
+    /// assert!(Angle::from_degrees(90.0).sin_fixed() > i32::MAX - 1000);
+    /// assert!(Angle::from_degrees(180.0).cos_fixed() < i32::MIN + 1000);
+    /// assert!(Angle::from_degrees(270.0).sin_fixed() < i32::MIN + 1000);
+    ///
+    /// // No angle should panic or wrap around the full circle.
+    /// for millidegree in (0..360_000).step_by(37) {
+    ///     let _ = Angle::from_degrees(millidegree as f64 / 1000.0).sin_cos_fixed();
+    /// }
+    /// ```
+    pub fn sin_cos_fixed(self) -> (i32, i32) {
+        let raw = self.as_u32();
+        let quadrant = raw >> 30;
+        let mut z = (raw & 0x3fff_ffff) as i32;
+
+        // The registers are carried in `i64`, because `sin`/`cos` of exactly `1.0` maps to `2^31`,
+        // one past what Q1.31 can hold in an `i32`; clamping (rather than overflowing) only
+        // happens once, on the way out.
+        let mut x = i64::from(CORDIC_GAIN_Q31);
+        let mut y = 0i64;
+        for (i, &atan) in CORDIC_ATAN_TABLE.iter().enumerate() {
+            let (dx, dy, dz) = (y >> i, x >> i, atan as i32);
+            if z >= 0 {
+                x -= dx;
+                y += dy;
+                z -= dz;
+            } else {
+                x += dx;
+                y -= dy;
+                z += dz;
+            }
+        }
+
+        let clamp = |v: i64| v.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32;
+
+        // x, y now approximate cos(r), sin(r) for r = the in-quadrant remainder; rotate them
+        // back into the quadrant the original angle actually fell in.
+        match quadrant {
+            0 => (clamp(y), clamp(x)),
+            1 => (clamp(x), clamp(-y)),
+            2 => (clamp(-y), clamp(-x)),
+            _ => (clamp(-x), clamp(y)),
+        }
+    }
+
+    /// Computes the sine of this angle using integer-only CORDIC, in Q1.31 fixed point.
+    #[inline]
+    pub fn sin_fixed(self) -> i32 {
+        self.sin_cos_fixed().0
+    }
+
+    /// Computes the cosine of this angle using integer-only CORDIC, in Q1.31 fixed point.
+    #[inline]
+    pub fn cos_fixed(self) -> i32 {
+        self.sin_cos_fixed().1
+    }
+
+    /// Computes the tangent of this angle using integer-only CORDIC, in Q16.16 fixed point (i.e.
+    /// `0x1_0000` represents `1.0`).
+    ///
+    /// Unlike `sin`/`cos`, `tan` is unbounded, so it cannot be represented in the same Q1.31
+    /// format as [`sin_cos_fixed`](Self::sin_cos_fixed) — that format can only hold magnitudes
+    /// below `1`, which would saturate for every angle past 45°. Q16.16 instead saturates to
+    /// `i32::MAX`/`i32::MIN` only once the angle gets within a few thousandths of a degree of an
+    /// exact right angle, where the mathematical tangent actually diverges.
+    ///
+    /// ```
+    /// use wrapang::Angle;
+    ///
+    /// let tan = Angle::from_degrees(45.0).tan_fixed();
+    /// assert!((tan - 0x1_0000).abs() < 100);
+    /// ```
+    pub fn tan_fixed(self) -> i32 {
+        let (sin, cos) = self.sin_cos_fixed();
+        let sin = i64::from(sin) << 16;
+        let cos = i64::from(cos);
+        if cos == 0 {
+            if sin >= 0 {
+                i32::MAX
+            } else {
+                i32::MIN
+            }
+        } else {
+            (sin / cos).clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32
+        }
+    }
+
     /// Rounds the angle to the nearest multiple of `unit`.
     ///
     /// This method treats the angle as unsigned.
@@ -257,6 +440,94 @@ impl Angle {
         };
         Angle::from_u32(rounded)
     }
+
+    /// Returns the angle directly opposite this one, i.e. rotated by [`HALF`] a circle.
+    #[inline]
+    #[must_use = "opposite() returns a new value and does not modify the receiver"]
+    pub fn opposite(self) -> Self {
+        self + HALF
+    }
+
+    /// Returns the signed shortest rotation from `other` to `self`, in the range `[-HALF, HALF)`.
+    ///
+    /// Thanks to the wrapping `u32` representation, this happens to be exactly `self - other`;
+    /// `signed_diff` exists to spell out that intent and to give [`bisect`](Self::bisect) and
+    /// [`lerp`](Self::lerp) a named way to reach across the wrap-around seam.
+    ///
+    /// Reinterpreted as `i32`, `u32::MAX / 2 + 1` (i.e. [`HALF`]) has no positive counterpart, so
+    /// an exactly antipodal pair (`self` and `other` differing by precisely half a circle) always
+    /// comes out as `-HALF`, never `+HALF`; [`bisect`](Self::bisect) inherits this and picks the
+    /// `-HALF`-ward side of two exactly opposite angles.
+    ///
+    /// ```
+    /// use wrapang::Angle;
+    ///
+    /// let diff = Angle::from_degrees(10.0).signed_diff(Angle::from_degrees(350.0));
+    /// assert_eq!(diff, Angle::from_degrees(20.0));
+    ///
+    /// let diff = Angle::from_degrees(350.0).signed_diff(Angle::from_degrees(10.0));
+    /// assert_eq!(diff, Angle::from_degrees(-20.0));
+    /// ```
+    #[inline]
+    #[must_use = "signed_diff() returns a new value and does not modify either operand"]
+    pub fn signed_diff(self, other: Angle) -> Self {
+        self - other
+    }
+
+    /// Returns the interior bisector of `self` and `other`, along their shorter arc.
+    ///
+    /// ```
+    /// use wrapang::Angle;
+    ///
+    /// // Bisecting across the wrap-around seam.
+    /// let bisected = Angle::from_degrees(350.0).bisect(Angle::from_degrees(10.0));
+    /// assert_eq!(bisected, Angle::from_degrees(0.0));
+    /// ```
+    #[must_use = "bisect() returns a new value and does not modify either operand"]
+    pub fn bisect(self, other: Angle) -> Self {
+        other + self.signed_diff(other) / 2
+    }
+
+    /// Interpolates between `self` and `other` along their shorter arc, where `t = 0.0` yields
+    /// `self` and `t = 1.0` yields `other`.
+    ///
+    /// ```
+    /// use wrapang::Angle;
+    ///
+    /// // Interpolating across the wrap-around seam.
+    /// let start = Angle::from_degrees(350.0);
+    /// let end = Angle::from_degrees(10.0);
+    /// assert_eq!(start.lerp(end, 0.5), Angle::from_degrees(0.0));
+    /// ```
+    #[must_use = "lerp() returns a new value and does not modify either operand"]
+    pub fn lerp(self, other: Angle, t: f64) -> Self {
+        let diff = f64::from(other.signed_diff(self).as_u32() as i32);
+        Angle(self.0 + Wrapping((diff * t).round() as i64 as u32))
+    }
+
+    /// Returns whether `self` and `other` are within `tolerance` of each other, measured along
+    /// their shorter arc.
+    ///
+    /// This is the wrap-aware, float-free equivalent of comparing two `f64`s for approximate
+    /// equality, which the `Div` docs recommend doing to guard against accumulated
+    /// rounding. See [`EPSILON`] and [`DEGREE`] for ready-made tolerances.
+    ///
+    /// ```
+    /// use wrapang::{Angle, DEGREE};
+    ///
+    /// let a = wrapang::HALF / 3 * 3;
+    /// assert_ne!(a, wrapang::HALF);
+    /// assert!(a.approx_eq(wrapang::HALF, DEGREE));
+    ///
+    /// // Works across the wrap-around seam too.
+    /// let a = Angle::from_degrees(359.9);
+    /// let b = Angle::from_degrees(0.05);
+    /// assert!(a.approx_eq(b, DEGREE));
+    /// ```
+    pub fn approx_eq(self, other: Angle, tolerance: Angle) -> bool {
+        let diff = self.signed_diff(other).as_u32() as i32;
+        diff.unsigned_abs() <= tolerance.as_u32()
+    }
 }
 
 impl fmt::Debug for Angle {
@@ -282,23 +553,24 @@ impl ops::Mul<u32> for Angle {
 /// # Warning
 /// ## Wrapping
 /// Due to wrapping, division might not work as expected.
-/// For example, `Angle::from_degrees(270) * 2 / 2` is equal to `Angle::from_degrees(90)` instead
-/// of the original value.
+/// For example, `Angle::from_degrees(90) * 2 / 2` is equal to `Angle::from_degrees(270)` instead
+/// of the original value, because doubling maps `90°` and `270°` (which differ by exactly half a
+/// circle) onto the same raw value, and halving can only recover one of the two.
 /// Always make sure that the divided angle is supposed to represent an angle in the range [-&pi;,
 /// &pi;].
 ///
 /// ```
 /// use wrapang::Angle;
 ///
-/// let angle = Angle::from_degrees(90.0);
-/// assert_eq!(angle * 2 / 2, Angle::from_degrees(90.0));
+/// let angle = Angle::from_degrees(45.0);
+/// assert_eq!(angle * 2 / 2, Angle::from_degrees(45.0));
 ///
-/// let angle = Angle::from_degrees(-90.0);
-/// assert_eq!(angle * 2 / 2, Angle::from_degrees(-90.0));
+/// let angle = Angle::from_degrees(-45.0);
+/// assert_eq!(angle * 2 / 2, Angle::from_degrees(-45.0));
 ///
-/// let big = Angle::from_degrees(270.0);
+/// let big = Angle::from_degrees(315.0);
 /// assert_eq!(angle, big);
-/// assert_eq!(angle / 2, Angle::from_degrees(-45.0));
+/// assert_eq!(angle / 2, Angle::from_degrees(-22.5));
 /// ```
 ///
 /// ## Precision
@@ -331,8 +603,183 @@ pub const SIXTH: Angle = Angle(Wrapping(0x_8000_0000 / 3));
 /// An angle of &pi;/2 radians (90 degrees).
 pub const QUARTER: Angle = Angle(Wrapping(0x_4000_0000));
 /// An angle approximating 2&pi;/3 radians (120 degrees).
-pub const THIRD: Angle = Angle(Wrapping((0x_1_0000_0000u64 / 3u64) as u32));
+pub const THIRD: Angle = Angle(Wrapping((0x0001_0000_0000_u64 / 3u64) as u32));
 /// An angle of &pi; radians (180 degress).
 pub const HALF: Angle = Angle(Wrapping(0x_8000_0000));
 /// An angle of 3&pi;/2 radians (270 degress).
 pub const COUN: Angle = Angle(Wrapping(0x_c000_0000));
+
+/// The smallest representable nonzero angle, one part in `2^32` of a whole circle.
+///
+/// Suitable as a [`Angle::approx_eq`] tolerance when two angles should have been computed
+/// identically and any difference is pure rounding noise.
+pub const EPSILON: Angle = Angle(Wrapping(1));
+
+/// An angle of one degree, for use as an [`Angle::approx_eq`] tolerance when comparing angles
+/// that have been through lossy operations such as `Div`/`Mul`.
+pub const DEGREE: Angle = Angle(Wrapping((0x0001_0000_0000_u64 / 360u64) as u32));
+
+/// Serde (de)serialization helpers for [`Angle`], gated behind the `serde` feature.
+///
+/// [`Angle`] itself derives `Serialize`/`Deserialize` to its raw `u32` representation, which is
+/// compact and round-trips exactly. The modules in here are meant for use with
+/// `#[serde(with = "...")]` on fields that would rather see a human-readable degrees/radians/unit
+/// number in formats like JSON, while still deserializing back through the same validated
+/// constructors (`from_degrees`/`from_radians`/`from_unit`) that the rest of the crate uses.
+#[cfg(feature = "serde")]
+pub mod serde {
+    use super::Angle;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    /// (De)serializes an [`Angle`] as an `f64` number of degrees, in `[0, 360)`.
+    ///
+    /// ```
+    /// use wrapang::Angle;
+    ///
+    /// #[derive(serde::Serialize, serde::Deserialize)]
+    /// struct Bearing(#[serde(with = "wrapang::serde::as_degrees")] Angle);
+    ///
+    /// let original = Bearing(Angle::from_degrees(200.0));
+    /// let json = serde_json::to_string(&original).unwrap();
+    /// let roundtripped: Bearing = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(roundtripped.0, original.0);
+    /// ```
+    pub mod as_degrees {
+        use super::{Angle, Deserialize, Deserializer, Serializer};
+
+        /// Serializes the angle as a number of degrees.
+        pub fn serialize<S>(angle: &Angle, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_f64(angle.as_degrees())
+        }
+
+        /// Deserializes the angle from a number of degrees.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Angle, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            f64::deserialize(deserializer).map(Angle::from_degrees)
+        }
+    }
+
+    /// (De)serializes an [`Angle`] as an `f64` number of radians, in `[0, 2π)`.
+    pub mod as_radians {
+        use super::{Angle, Deserialize, Deserializer, Serializer};
+
+        /// Serializes the angle as a number of radians.
+        pub fn serialize<S>(angle: &Angle, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_f64(angle.as_radians())
+        }
+
+        /// Deserializes the angle from a number of radians.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Angle, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            f64::deserialize(deserializer).map(Angle::from_radians)
+        }
+    }
+
+    /// (De)serializes an [`Angle`] as an `f64` fraction of a whole circle, in `[0, 1)`.
+    ///
+    /// ```
+    /// use wrapang::Angle;
+    ///
+    /// #[derive(serde::Serialize, serde::Deserialize)]
+    /// struct Bearing(#[serde(with = "wrapang::serde::as_unit")] Angle);
+    ///
+    /// let original = Bearing(Angle::from_degrees(200.0));
+    /// let json = serde_json::to_string(&original).unwrap();
+    /// let roundtripped: Bearing = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(roundtripped.0, original.0);
+    /// ```
+    pub mod as_unit {
+        use super::{Angle, Deserialize, Deserializer, Serializer};
+
+        /// Serializes the angle as a fraction of a whole circle.
+        pub fn serialize<S>(angle: &Angle, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_f64(angle.as_unit())
+        }
+
+        /// Deserializes the angle from a fraction of a whole circle.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Angle, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            f64::deserialize(deserializer).map(Angle::from_unit)
+        }
+    }
+}
+
+/// `rand` support for [`Angle`], gated behind the `rand` feature.
+///
+/// Since the whole premise of this crate is a uniformly-distributed `u32` field, sampling a
+/// uniform random `Angle` is as simple as sampling a uniform random `u32` directly, with no float
+/// conversion and no modulo bias.
+#[cfg(feature = "rand")]
+pub mod rand {
+    use super::Angle;
+    use ::rand::distributions::{Distribution, Standard};
+    use ::rand::Rng;
+
+    impl Distribution<Angle> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle {
+            Angle::from_u32(rng.gen())
+        }
+    }
+
+    /// A [`Distribution`] sampling a uniform angle within the arc from `start` to `end`, going in
+    /// the direction of increasing `u32` values and wrapping around the seam correctly if that
+    /// means crossing back past zero.
+    ///
+    /// If `start == end`, the arc is taken to cover the whole circle.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// use rand::rngs::StdRng;
+    /// use wrapang::{rand::UniformArc, Angle};
+    ///
+    /// // An arc that wraps around the seam, e.g. a compass bearing "northerly" range.
+    /// let arc = UniformArc::new(Angle::from_degrees(315.0), Angle::from_degrees(45.0));
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// for _ in 0..1000 {
+    ///     let sampled: Angle = rng.sample(arc);
+    ///     let degrees = sampled.as_degrees();
+    ///     assert!(degrees >= 315.0 || degrees < 45.0);
+    /// }
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub struct UniformArc {
+        start: Angle,
+        span: u32,
+    }
+
+    impl UniformArc {
+        /// Creates a sampler for the arc `[start, end)`.
+        pub fn new(start: Angle, end: Angle) -> Self {
+            Self {
+                start,
+                span: end.as_u32().wrapping_sub(start.as_u32()),
+            }
+        }
+    }
+
+    impl Distribution<Angle> for UniformArc {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle {
+            let offset = if self.span == 0 {
+                rng.gen::<u32>()
+            } else {
+                rng.gen_range(0..self.span)
+            };
+            self.start + Angle::from_u32(offset)
+        }
+    }
+}